@@ -0,0 +1,130 @@
+use std::cmp::Ordering;
+
+use crate::Rect;
+
+/// Configures how small items are folded into a single "Other" tile before
+/// layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AggregateConfig {
+    /// Items whose value is less than this fraction of the total are merged
+    /// into a single "Other" entry. `0.0` disables aggregation.
+    pub min_fraction: f64,
+}
+
+impl AggregateConfig {
+    /// Merge any item below `min_fraction` of the total into "Other".
+    pub fn new(min_fraction: f64) -> Self {
+        Self { min_fraction }
+    }
+}
+
+impl Default for AggregateConfig {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+/// Identifies what an [`Aggregated`] rectangle represents: either a single
+/// original item, or a synthesized "Other" tile standing in for every item
+/// folded into it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AggregatedItem {
+    /// The index of the original item this rectangle was placed for.
+    Item(usize),
+    /// The indices of the original items merged into this "Other" tile.
+    Other(Vec<usize>),
+}
+
+/// The result of [`squarify_aggregated`]: each entry in `rects` corresponds
+/// to the entry at the same position in `items`.
+#[derive(Debug, Clone)]
+pub struct Aggregated {
+    pub rects: Vec<Rect>,
+    pub items: Vec<AggregatedItem>,
+}
+
+/// Lay out `sizes` as a treemap, first folding every item below
+/// `config.min_fraction` of the total into a single combined "Other" value.
+/// This bounds the number of rectangles produced regardless of how long the
+/// tail of small values is; use the returned [`AggregatedItem::Other`]
+/// indices to render an "Other (N items)" tile.
+pub fn squarify_aggregated<I>(
+    sizes: &[f64],
+    x: f64,
+    y: f64,
+    dx: f64,
+    dy: f64,
+    padding: I,
+    config: AggregateConfig,
+) -> Aggregated
+where
+    I: Into<Option<f64>>,
+{
+    let total: f64 = sizes.iter().sum();
+    let threshold = config.min_fraction * total;
+
+    let mut merged_indices = Vec::new();
+    let mut merged_total = 0.0;
+    let mut entries: Vec<(AggregatedItem, f64)> = Vec::new();
+
+    for (i, &value) in sizes.iter().enumerate() {
+        if total > 0.0 && value < threshold {
+            merged_indices.push(i);
+            merged_total += value;
+        } else {
+            entries.push((AggregatedItem::Item(i), value));
+        }
+    }
+
+    if !merged_indices.is_empty() {
+        entries.push((AggregatedItem::Other(merged_indices), merged_total));
+    }
+
+    entries.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+
+    let values: Vec<f64> = entries.iter().map(|(_, value)| *value).collect();
+    let rects = crate::squarify(&values, x, y, dx, dy, padding);
+    let items = entries.into_iter().map(|(item, _)| item).collect();
+
+    Aggregated { rects, items }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sub_threshold_items_are_merged_into_other() {
+        let sizes = vec![50.0, 30.0, 1.0, 1.0, 1.0];
+        let config = AggregateConfig::new(0.05);
+
+        let aggregated = squarify_aggregated(&sizes, 0.0, 0.0, 100.0, 100.0, None, config);
+
+        assert_eq!(aggregated.rects.len(), aggregated.items.len());
+
+        let other = aggregated
+            .items
+            .iter()
+            .find_map(|item| match item {
+                AggregatedItem::Other(indices) => Some(indices),
+                AggregatedItem::Item(_) => None,
+            })
+            .expect("one Other entry should be present");
+
+        let mut other = other.clone();
+        other.sort_unstable();
+        assert_eq!(other, vec![2, 3, 4]);
+
+        let kept: Vec<usize> = aggregated
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                AggregatedItem::Item(i) => Some(*i),
+                AggregatedItem::Other(_) => None,
+            })
+            .collect();
+        assert_eq!(kept.len(), 2);
+        assert!(kept.contains(&0));
+        assert!(kept.contains(&1));
+    }
+}
@@ -0,0 +1,171 @@
+use crate::layout_builder::{apply_margin, Margin};
+use crate::{normalized_sizes, Rect};
+
+/// Which layout strategy [`squarify_with`] should use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutKind {
+    /// The default squarified algorithm: grows each row to keep rectangles
+    /// as close to square as possible, at the cost of reordering items.
+    Squarified,
+    /// Subdivides the rectangle proportionally along a single axis (always
+    /// horizontal). Preserves input order exactly.
+    ///
+    /// This is a flat, one-shot layout, so there is no recursion depth to
+    /// alternate axis against here; [`squarify_tree`](crate::squarify_tree)
+    /// is the crate's only recursive layout today and it hardcodes
+    /// [`LayoutKind::Squarified`], so axis-alternating slice-and-dice has no
+    /// caller to serve. A future recursive `SliceAndDice` consumer would
+    /// need to thread its own depth through and pick the axis itself before
+    /// calling [`squarify_with`].
+    SliceAndDice,
+    /// Lays items left-to-right into a strip, starting a new strip
+    /// whenever the next item would worsen the strip's average aspect
+    /// ratio. A middle ground between order-preservation and squareness.
+    Strip,
+}
+
+/// Lay out `sizes` into `rect` using the chosen [`LayoutKind`].
+pub fn squarify_with<I>(sizes: &[f64], rect: &Rect, kind: LayoutKind, padding: I) -> Vec<Rect>
+where
+    I: Into<Option<f64>>,
+{
+    let padding = padding.into();
+
+    match kind {
+        LayoutKind::Squarified => crate::squarify(sizes, rect.x, rect.y, rect.dx, rect.dy, padding),
+        LayoutKind::SliceAndDice => {
+            let mut rects = slice_and_dice(sizes, rect.x, rect.y, rect.dx, rect.dy);
+            if let Some(pad) = padding {
+                apply_margin(&mut rects, Margin::new(pad, pad));
+            }
+            rects
+        }
+        LayoutKind::Strip => {
+            let total: f64 = sizes.iter().sum();
+            if sizes.is_empty() || total <= 0.0 {
+                return Vec::with_capacity(0);
+            }
+            let sizes = normalized_sizes(sizes, rect.dx, rect.dy);
+            let mut rects = strip(&sizes, rect.x, rect.y, rect.dx, rect.dy);
+            if let Some(pad) = padding {
+                apply_margin(&mut rects, Margin::new(pad, pad));
+            }
+            rects
+        }
+    }
+}
+
+/// Always slices along the horizontal axis. See [`LayoutKind::SliceAndDice`]
+/// for why this doesn't alternate axis by recursion depth: this function
+/// isn't itself recursive, and no caller in this crate recurses through it.
+fn slice_and_dice(sizes: &[f64], x: f64, y: f64, dx: f64, dy: f64) -> Vec<Rect> {
+    let total: f64 = sizes.iter().sum();
+    if sizes.is_empty() || total <= 0.0 {
+        return Vec::with_capacity(0);
+    }
+
+    let mut rects = Vec::with_capacity(sizes.len());
+    let mut cur_x = x;
+    for &size in sizes {
+        let width = dx * (size / total);
+        rects.push(Rect::new(cur_x, y, width, dy));
+        cur_x += width;
+    }
+    rects
+}
+
+fn strip(sizes: &[f64], x: f64, y: f64, dx: f64, dy: f64) -> Vec<Rect> {
+    if sizes.is_empty() {
+        return Vec::with_capacity(0);
+    } else if sizes.len() == 1 {
+        return vec![Rect::new(x, y, dx, dy)];
+    }
+
+    let mut idx = 1;
+    while idx < sizes.len()
+        && avg_ratio(&sizes[..idx], x, y, dx, dy) >= avg_ratio(&sizes[..=idx], x, y, dx, dy)
+    {
+        idx += 1;
+    }
+
+    let current = &sizes[..idx];
+    let remaining = &sizes[idx..];
+    let lover = leftover_strip(current, x, y, dx, dy);
+
+    let mut finished = layout_strip(current, x, y, dx, dy);
+    let rest = strip(remaining, lover.x, lover.y, lover.dx, lover.dy);
+    finished.extend(rest);
+    finished
+}
+
+/// Lay `sizes` left-to-right into a single strip of height `covered_area / dx`.
+fn layout_strip(sizes: &[f64], mut x: f64, y: f64, dx: f64, _dy: f64) -> Vec<Rect> {
+    let covered_area: f64 = sizes.iter().sum();
+    let height = covered_area / dx;
+    let mut rects = Vec::with_capacity(sizes.len());
+    for size in sizes {
+        let rect = Rect::new(x, y, size / height, height);
+        rects.push(rect);
+        x += size / height;
+    }
+    rects
+}
+
+fn leftover_strip(sizes: &[f64], x: f64, y: f64, dx: f64, dy: f64) -> Rect {
+    let covered_area: f64 = sizes.iter().sum();
+    let height = covered_area / dx;
+    Rect::new(x, y + height, dx, dy - height)
+}
+
+fn avg_ratio(sizes: &[f64], x: f64, y: f64, dx: f64, dy: f64) -> f64 {
+    let rects = layout_strip(sizes, x, y, dx, dy);
+    let ratio_sum: f64 = rects
+        .iter()
+        .map(|r| f64::max(r.dx / r.dy, r.dy / r.dx))
+        .sum();
+    ratio_sum / rects.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FLOAT_MARGIN_OF_ERROR: f64 = 0.000001;
+    const SIZES: [f64; 6] = [500.0, 433.0, 78.0, 25.0, 25.0, 7.0];
+
+    fn assert_fills_rect(rects: &[Rect], rect: &Rect) {
+        assert!(!rects.is_empty());
+        let max_x = rects.iter().map(|r| r.x + r.dx).fold(f64::MIN, f64::max);
+        let max_y = rects.iter().map(|r| r.y + r.dy).fold(f64::MIN, f64::max);
+        assert!((max_x - (rect.x + rect.dx)).abs() < FLOAT_MARGIN_OF_ERROR);
+        assert!((max_y - (rect.y + rect.dy)).abs() < FLOAT_MARGIN_OF_ERROR);
+    }
+
+    #[test]
+    fn squarified_fills_rect() {
+        let rect = Rect::new(0.0, 0.0, 700.0, 433.0);
+        let rects = squarify_with(&SIZES, &rect, LayoutKind::Squarified, None);
+        assert_fills_rect(&rects, &rect);
+    }
+
+    #[test]
+    fn slice_and_dice_fills_rect() {
+        let rect = Rect::new(0.0, 0.0, 700.0, 433.0);
+        let rects = squarify_with(&SIZES, &rect, LayoutKind::SliceAndDice, None);
+        assert_fills_rect(&rects, &rect);
+    }
+
+    #[test]
+    fn strip_fills_rect() {
+        let rect = Rect::new(0.0, 0.0, 700.0, 433.0);
+        let rects = squarify_with(&SIZES, &rect, LayoutKind::Strip, None);
+        assert_fills_rect(&rects, &rect);
+    }
+
+    #[test]
+    fn strip_returns_empty_for_all_zero_sizes() {
+        let rect = Rect::new(0.0, 0.0, 700.0, 433.0);
+        let rects = squarify_with(&[0.0, 0.0], &rect, LayoutKind::Strip, None);
+        assert!(rects.is_empty());
+    }
+}
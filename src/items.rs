@@ -0,0 +1,86 @@
+use std::cmp::Ordering;
+
+use crate::{squarify, Rect};
+
+/// Lay out `items` as a treemap and return each item paired with its placed
+/// rectangle, so callers don't have to zip the result back against their
+/// data by index.
+///
+/// Internally this sorts by descending size the way [`squarify`] expects,
+/// then lays out and re-associates the original items with their rectangles.
+///
+/// # Example
+///
+/// ```
+/// use squarify::squarify_items;
+///
+/// struct File {
+///     name: &'static str,
+///     bytes: f64,
+/// }
+///
+/// let files = vec![
+///     File { name: "a.txt", bytes: 100.0 },
+///     File { name: "b.txt", bytes: 50.0 },
+/// ];
+///
+/// let placed = squarify_items(&files, |f| f.bytes, 0.0, 0.0, 100.0, 100.0, None);
+/// for (file, rect) in placed {
+///     println!("{} -> {:?}", file.name, rect);
+/// }
+/// ```
+pub fn squarify_items<T, F, I>(
+    items: &[T],
+    size: F,
+    x: f64,
+    y: f64,
+    dx: f64,
+    dy: f64,
+    padding: I,
+) -> Vec<(&T, Rect)>
+where
+    F: Fn(&T) -> f64,
+    I: Into<Option<f64>>,
+{
+    let mut indexed: Vec<(&T, f64)> = items.iter().map(|item| (item, size(item))).collect();
+    indexed.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+
+    let sizes: Vec<f64> = indexed.iter().map(|(_, size)| *size).collect();
+    let rects = squarify(&sizes, x, y, dx, dy, padding);
+
+    indexed.into_iter().map(|(item, _)| item).zip(rects).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct File {
+        name: &'static str,
+        bytes: f64,
+    }
+
+    #[test]
+    fn pairs_each_item_with_its_own_rect() {
+        let files = vec![
+            File { name: "a.txt", bytes: 10.0 },
+            File { name: "b.txt", bytes: 100.0 },
+            File { name: "c.txt", bytes: 50.0 },
+        ];
+
+        let placed = squarify_items(&files, |f| f.bytes, 0.0, 0.0, 100.0, 100.0, None);
+
+        assert_eq!(placed.len(), files.len());
+        for file in &files {
+            assert!(placed.iter().any(|(item, _)| *item == file));
+        }
+
+        let (biggest, rect) = placed
+            .iter()
+            .max_by(|a, b| a.0.bytes.partial_cmp(&b.0.bytes).unwrap())
+            .unwrap();
+        assert_eq!(biggest.name, "b.txt");
+        assert!(rect.dx * rect.dy > 0.0);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{_squarify, normalized_sizes, Rect};
+
+/// Independent horizontal and vertical insets applied around each rectangle,
+/// as an alternative to a single symmetric `padding` value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Margin {
+    pub horizontal: f64,
+    pub vertical: f64,
+}
+
+impl Margin {
+    /// Create a margin with the given horizontal and vertical insets.
+    pub fn new(horizontal: f64, vertical: f64) -> Self {
+        Self { horizontal, vertical }
+    }
+}
+
+impl Default for Margin {
+    fn default() -> Self {
+        Self::new(0.0, 0.0)
+    }
+}
+
+/// The corner of the bounding rectangle that the layout grows from.
+///
+/// `squarify`'s coordinates naturally grow down and to the right, which
+/// matches a top-left origin. Since some coordinate systems (most GUI and
+/// SVG canvases) put `y = 0` at the top while others put it at the bottom,
+/// picking a different corner flips the `x` and/or `y` of every returned
+/// [`Rect`] so callers don't have to post-process the output themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Corner {
+    #[default]
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// A builder for configuring squarify layouts beyond the single symmetric
+/// `padding` value the free [`crate::squarify`] function takes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Layout {
+    margin: Margin,
+    corner: Corner,
+}
+
+impl Layout {
+    /// Create a layout with no margin and a top-left origin.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the per-side margin applied around each rectangle.
+    pub fn margin(mut self, margin: Margin) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Set the corner the layout grows from.
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Lay out `sizes` into the rectangle described by `x, y, dx, dy`,
+    /// applying this layout's margin and corner.
+    ///
+    /// A single item fills the whole rect outright with no margin applied,
+    /// matching `squarify`'s original behavior (there's no neighboring rect
+    /// to create breathing room against).
+    pub fn squarify(&self, sizes: &[f64], x: f64, y: f64, dx: f64, dy: f64) -> Vec<Rect> {
+        if sizes.len() == 1 {
+            let mut rects = vec![Rect::new(x, y, dx, dy)];
+            flip_to_corner(&mut rects, x, y, dx, dy, self.corner);
+            return rects;
+        }
+
+        let mut rects = if sizes.is_empty() {
+            Vec::with_capacity(0)
+        } else {
+            let normalized = normalized_sizes(sizes, dx, dy);
+            _squarify(&normalized, x, y, dx, dy)
+        };
+
+        apply_margin(&mut rects, self.margin);
+        flip_to_corner(&mut rects, x, y, dx, dy, self.corner);
+        rects
+    }
+}
+
+pub(crate) fn apply_margin(rects: &mut [Rect], margin: Margin) {
+    for rect in rects.iter_mut() {
+        if rect.dx > margin.horizontal {
+            rect.x += margin.horizontal / 2.0;
+            rect.dx -= margin.horizontal;
+        }
+        if rect.dy > margin.vertical {
+            rect.y += margin.vertical / 2.0;
+            rect.dy -= margin.vertical;
+        }
+    }
+}
+
+fn flip_to_corner(rects: &mut [Rect], x: f64, y: f64, dx: f64, dy: f64, corner: Corner) {
+    let flip_x = matches!(corner, Corner::TopRight | Corner::BottomRight);
+    let flip_y = matches!(corner, Corner::BottomLeft | Corner::BottomRight);
+
+    for rect in rects.iter_mut() {
+        if flip_x {
+            rect.x = 2.0 * x + dx - rect.x - rect.dx;
+        }
+        if flip_y {
+            rect.y = 2.0 * y + dy - rect.y - rect.dy;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FLOAT_MARGIN_OF_ERROR: f64 = 0.000001;
+
+    #[test]
+    fn single_item_fills_rect_with_no_margin_applied() {
+        let rects = Layout::new()
+            .margin(Margin::new(10.0, 10.0))
+            .squarify(&[100.0], 0.0, 0.0, 200.0, 100.0);
+
+        assert_eq!(rects.len(), 1);
+        assert_eq!(rects[0].x, 0.0);
+        assert_eq!(rects[0].y, 0.0);
+        assert_eq!(rects[0].dx, 200.0);
+        assert_eq!(rects[0].dy, 100.0);
+    }
+
+    #[test]
+    fn bottom_right_corner_flips_x_and_y() {
+        let sizes = vec![500.0, 433.0, 78.0, 25.0, 25.0, 7.0];
+        let (x, y, dx, dy) = (0.0, 0.0, 700.0, 433.0);
+
+        let top_left = Layout::new().squarify(&sizes, x, y, dx, dy);
+        let bottom_right = Layout::new()
+            .corner(Corner::BottomRight)
+            .squarify(&sizes, x, y, dx, dy);
+
+        assert_eq!(top_left.len(), bottom_right.len());
+        for (expected, flipped) in top_left.iter().zip(bottom_right.iter()) {
+            let want_x = 2.0 * x + dx - expected.x - expected.dx;
+            let want_y = 2.0 * y + dy - expected.y - expected.dy;
+            assert!((flipped.x - want_x).abs() < FLOAT_MARGIN_OF_ERROR);
+            assert!((flipped.y - want_y).abs() < FLOAT_MARGIN_OF_ERROR);
+            assert!((flipped.dx - expected.dx).abs() < FLOAT_MARGIN_OF_ERROR);
+            assert!((flipped.dy - expected.dy).abs() < FLOAT_MARGIN_OF_ERROR);
+        }
+    }
+}
@@ -5,6 +5,22 @@
 //! This is a direct translation of the python implementation by
 //! [here](https://github.com/laserson/squarify).
 
+mod aggregate;
+mod algorithm;
+mod items;
+mod layout_builder;
+#[cfg(feature = "svg")]
+mod svg;
+mod tree;
+
+pub use aggregate::{squarify_aggregated, Aggregated, AggregateConfig, AggregatedItem};
+pub use algorithm::{squarify_with, LayoutKind};
+pub use items::squarify_items;
+pub use layout_builder::{Corner, Layout, Margin};
+#[cfg(feature = "svg")]
+pub use svg::{to_svg, TileStyle};
+pub use tree::{squarify_tree, Node, PlacedRect};
+
 /// Represents a rectangle with an x and y coordinate,
 /// as well as a width (dx) and height (dy)
 #[derive(Debug, Clone)]
@@ -59,42 +75,45 @@ pub fn squarify<I>(sizes: &[f64], x: f64, y: f64, dx: f64, dy: f64, padding: I)
 where
     I: Into<Option<f64>>,
 {
-    if sizes.is_empty() {
-        return Vec::with_capacity(0);
-    } else if sizes.len() == 1 {
-        return vec![Rect::new(x, y, dx, dy)];
-    }
-
-    let sizes = normalized_sizes(sizes, dx, dy);
-    let mut rects = _squarify(&sizes, x, y, dx, dy);
-
-    if let Some(pad) = padding.into() {
-        for rect in rects.iter_mut() {
-            if rect.dx > pad {
-                rect.x += pad / 2.0;
-                rect.dx -= pad;
-            }
-            if rect.dy > pad {
-                rect.y += pad / 2.0;
-                rect.dy -= pad;
-            }
-        }
-    }
-
-    rects
+    let pad = padding.into().unwrap_or(0.0);
+    Layout::default()
+        .margin(Margin::new(pad, pad))
+        .squarify(sizes, x, y, dx, dy)
 }
 
-fn _squarify(sizes: &[f64], x: f64, y: f64, dx: f64, dy: f64) -> Vec<Rect> {
+pub(crate) fn _squarify(sizes: &[f64], x: f64, y: f64, dx: f64, dy: f64) -> Vec<Rect> {
     if sizes.is_empty() {
         return Vec::with_capacity(0);
     } else if sizes.len() == 1 {
         return layout(sizes, x, y, dx, dy);
     }
 
+    // Grow the row incrementally instead of calling `layout` (which
+    // allocates a fresh `Vec<Rect>`) for every candidate row length. The
+    // worst aspect ratio of a row laid into a strip of fixed side
+    // `shorter_dim` is a closed form of the row's running sum and its
+    // smallest/largest member, so it can be tracked without materializing
+    // any rectangles until the row is finalized.
+    let shorter_dim = dx.min(dy);
     let mut idx = 1;
-    while idx < sizes.len()
-        && worst_ratio(&sizes[..idx], x, y, dx, dy) >= worst_ratio(&sizes[..=1], x, y, dx, dy)
-    {
+    let mut row_sum = sizes[0];
+    let mut row_min = sizes[0];
+    let mut row_max = sizes[0];
+    let mut worst = row_worst_ratio(row_sum, row_min, row_max, shorter_dim);
+
+    while idx < sizes.len() {
+        let next = sizes[idx];
+        let candidate_sum = row_sum + next;
+        let candidate_min = row_min.min(next);
+        let candidate_max = row_max.max(next);
+        let candidate_worst = row_worst_ratio(candidate_sum, candidate_min, candidate_max, shorter_dim);
+        if candidate_worst > worst {
+            break;
+        }
+        row_sum = candidate_sum;
+        row_min = candidate_min;
+        row_max = candidate_max;
+        worst = candidate_worst;
         idx += 1;
     }
 
@@ -108,7 +127,18 @@ fn _squarify(sizes: &[f64], x: f64, y: f64, dx: f64, dy: f64) -> Vec<Rect> {
     finished
 }
 
-fn normalized_sizes(sizes: &[f64], dx: f64, dy: f64) -> Vec<f64> {
+/// The worst (largest) aspect ratio among rectangles in a row of total area
+/// `row_sum`, laid out along a strip of fixed side `shorter_dim`, given the
+/// row's smallest and largest member areas. Equivalent to laying the row
+/// out via `layout` and taking the worst ratio of the resulting rects, but
+/// without building any `Rect`s.
+fn row_worst_ratio(row_sum: f64, row_min: f64, row_max: f64, shorter_dim: f64) -> f64 {
+    let width = row_sum / shorter_dim;
+    let width_sq = width * width;
+    f64::max(width_sq / row_min, row_max / width_sq)
+}
+
+pub(crate) fn normalized_sizes(sizes: &[f64], dx: f64, dy: f64) -> Vec<f64> {
     let total_size: f64 = sizes.iter().sum();
     let total_area = dx * dy;
     sizes.iter().map(|x| x * total_area / total_size).collect()
@@ -169,13 +199,6 @@ fn leftover_col(sizes: &[f64], x: f64, y: f64, dx: f64, dy: f64) -> Rect {
     Rect::new(x, y + height, dx, dy - height)
 }
 
-fn worst_ratio(sizes: &[f64], x: f64, y: f64, dx: f64, dy: f64) -> f64 {
-    layout(sizes, x, y, dx, dy)
-        .into_iter()
-        .map(|x| f64::max(x.dx / x.dy, x.dy / x.dx))
-        .fold(std::f64::NAN, f64::max)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,7 +241,7 @@ mod tests {
         ];
         let observed = squarify(&values, 0.0, 0.0, 700.0, 433.0, None);
         assert_eq!(expected.len(), observed.len());
-        for (o, e) in observed.into_iter().zip(expected.into_iter()) {
+        for (o, e) in observed.into_iter().zip(expected) {
             assert!((o.x - e.x).abs() < FLOAT_MARGIN_OF_ERROR);
             assert!((o.y - e.y).abs() < FLOAT_MARGIN_OF_ERROR);
             assert!((o.dx - e.dx).abs() < FLOAT_MARGIN_OF_ERROR);
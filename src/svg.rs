@@ -0,0 +1,141 @@
+use crate::Rect;
+
+const FONT_SIZE: f64 = 12.0;
+const LABEL_PADDING: f64 = 4.0;
+
+/// Per-tile styling supplied to [`to_svg`]: a fill color, an optional
+/// stroke, and an optional text label.
+#[derive(Debug, Clone, Default)]
+pub struct TileStyle {
+    pub fill: String,
+    pub stroke: Option<String>,
+    pub label: Option<String>,
+}
+
+impl TileStyle {
+    /// A tile with the given fill and no stroke or label.
+    pub fn new(fill: impl Into<String>) -> Self {
+        Self {
+            fill: fill.into(),
+            stroke: None,
+            label: None,
+        }
+    }
+
+    /// Set the stroke color.
+    pub fn stroke(mut self, stroke: impl Into<String>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// Set the text label drawn centered in the tile.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// Render a treemap layout as a complete `<svg>` document, one `<rect>`
+/// (and optional `<text>`) per tile sized to the overall bounding box of
+/// `rects`.
+///
+/// `style` is called once per rectangle (with its index) to get its fill,
+/// stroke, and label. A label is only drawn when the tile is large enough
+/// to plausibly fit it, estimated from the label's character count and the
+/// fixed font size; otherwise it's silently dropped so tiny tiles stay
+/// clean instead of overflowing with clipped text.
+pub fn to_svg<F>(rects: &[Rect], style: F) -> String
+where
+    F: Fn(usize, &Rect) -> TileStyle,
+{
+    if rects.is_empty() {
+        return r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="0"></svg>"#.to_string();
+    }
+
+    let min_x = rects.iter().map(|r| r.x).fold(f64::INFINITY, f64::min);
+    let min_y = rects.iter().map(|r| r.y).fold(f64::INFINITY, f64::min);
+    let max_x = rects.iter().map(|r| r.x + r.dx).fold(f64::NEG_INFINITY, f64::max);
+    let max_y = rects.iter().map(|r| r.y + r.dy).fold(f64::NEG_INFINITY, f64::max);
+    let width = max_x - min_x;
+    let height = max_y - min_y;
+
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{min_x} {min_y} {width} {height}" width="{width}" height="{height}">"#
+    );
+
+    for (i, rect) in rects.iter().enumerate() {
+        let tile = style(i, rect);
+
+        svg.push_str(&format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}""#,
+            rect.x,
+            rect.y,
+            rect.dx,
+            rect.dy,
+            escape_attr(&tile.fill)
+        ));
+        if let Some(stroke) = &tile.stroke {
+            svg.push_str(&format!(r#" stroke="{}""#, escape_attr(stroke)));
+        }
+        svg.push_str("/>");
+
+        if let Some(label) = tile.label.as_deref() {
+            if fits_label(label, rect) {
+                let clip_id = format!("squarify-clip-{i}");
+                svg.push_str(&format!(
+                    r#"<clipPath id="{clip_id}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+                    rect.x, rect.y, rect.dx, rect.dy
+                ));
+                svg.push_str(&format!(
+                    r#"<text x="{}" y="{}" font-size="{FONT_SIZE}" text-anchor="middle" dominant-baseline="middle" clip-path="url(#{clip_id})">{}</text>"#,
+                    rect.x + rect.dx / 2.0,
+                    rect.y + rect.dy / 2.0,
+                    escape_text(label)
+                ));
+            }
+        }
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+/// A rough label-fitting rule: hide the label when the tile is smaller than
+/// the text's estimated extent (character count times a fixed per-character
+/// width at `FONT_SIZE`), so tiny tiles don't get overrun with text.
+fn fits_label(label: &str, rect: &Rect) -> bool {
+    let text_width = label.chars().count() as f64 * FONT_SIZE * 0.6;
+    rect.dx - LABEL_PADDING >= text_width && rect.dy - LABEL_PADDING >= FONT_SIZE
+}
+
+fn escape_attr(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn escape_text(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_one_rect_per_tile_and_hides_labels_that_do_not_fit() {
+        let rects = vec![
+            Rect::new(0.0, 0.0, 100.0, 100.0),
+            Rect::new(100.0, 0.0, 5.0, 5.0),
+        ];
+
+        let svg = to_svg(&rects, |i, _| {
+            TileStyle::new("red").label(if i == 0 { "big tile" } else { "tiny" })
+        });
+
+        assert_eq!(svg.matches(r#"fill="red""#).count(), 2);
+        assert!(svg.contains(">big tile<"));
+        assert!(!svg.contains(">tiny<"));
+    }
+}
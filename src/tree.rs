@@ -0,0 +1,150 @@
+use std::cmp::Ordering;
+
+use crate::{squarify, Rect};
+
+/// A node in a hierarchical treemap. Leaves carry a size; branches carry
+/// children whose sizes are summed bottom-up to determine the branch's area.
+#[derive(Debug, Clone)]
+pub enum Node {
+    Leaf { value: f64 },
+    Branch { children: Vec<Node> },
+}
+
+impl Node {
+    /// Create a leaf node with the given value.
+    pub fn leaf(value: f64) -> Self {
+        Node::Leaf { value }
+    }
+
+    /// Create a branch node from a list of children.
+    pub fn branch(children: Vec<Node>) -> Self {
+        Node::Branch { children }
+    }
+
+    /// The total area this node occupies, i.e. the sum of all leaf values
+    /// beneath it.
+    fn total_value(&self) -> f64 {
+        match self {
+            Node::Leaf { value } => value.max(0.0),
+            Node::Branch { children } => children.iter().map(Node::total_value).sum(),
+        }
+    }
+}
+
+/// A rectangle placed by [`squarify_tree`], tagged with its depth in the
+/// tree and the path of child indices leading to it from the root.
+#[derive(Debug, Clone)]
+pub struct PlacedRect {
+    pub rect: Rect,
+    pub depth: usize,
+    pub path: Vec<usize>,
+}
+
+/// Per-level spacing used while laying out a [`Node`] tree: `padding` is
+/// applied the same way [`squarify`] applies it, and `header` reserves a
+/// strip at the top of each branch's rectangle (e.g. for drawing a label)
+/// before its children are laid out.
+struct TreeStyle {
+    padding: f64,
+    header: f64,
+}
+
+/// Lay out a [`Node`] tree into nested treemap rectangles.
+///
+/// Each branch's area is its leaves' total value, squarified within its
+/// parent's rectangle; each child rectangle is then recursed into.
+///
+/// Zero-value leaves and branches with no positive-value descendants are
+/// skipped entirely, so no degenerate rectangle with a zero width or height
+/// is ever produced.
+pub fn squarify_tree(root: &Node, x: f64, y: f64, dx: f64, dy: f64, padding: f64, header: f64) -> Vec<PlacedRect> {
+    let mut out = Vec::new();
+    let mut path = Vec::new();
+    let style = TreeStyle { padding, header };
+    layout_node(root, Rect::new(x, y, dx, dy), &style, 0, &mut path, &mut out);
+    out
+}
+
+fn layout_node(node: &Node, rect: Rect, style: &TreeStyle, depth: usize, path: &mut Vec<usize>, out: &mut Vec<PlacedRect>) {
+    if node.total_value() <= 0.0 || rect.dx <= 0.0 || rect.dy <= 0.0 {
+        return;
+    }
+
+    out.push(PlacedRect {
+        rect: rect.clone(),
+        depth,
+        path: path.clone(),
+    });
+
+    let children = match node {
+        Node::Leaf { .. } => return,
+        Node::Branch { children } => children,
+    };
+
+    let (y, dy) = if style.header > 0.0 && rect.dy > style.header {
+        (rect.y + style.header, rect.dy - style.header)
+    } else {
+        (rect.y, rect.dy)
+    };
+    if rect.dx <= 0.0 || dy <= 0.0 {
+        return;
+    }
+
+    let mut sized: Vec<(usize, f64)> = children
+        .iter()
+        .enumerate()
+        .map(|(i, child)| (i, child.total_value()))
+        .filter(|(_, value)| *value > 0.0)
+        .collect();
+    sized.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+
+    let sizes: Vec<f64> = sized.iter().map(|(_, value)| *value).collect();
+    let rects = squarify(&sizes, rect.x, y, rect.dx, dy, style.padding);
+
+    for ((child_idx, _), child_rect) in sized.into_iter().zip(rects) {
+        if child_rect.dx <= 0.0 || child_rect.dy <= 0.0 {
+            continue;
+        }
+        path.push(child_idx);
+        layout_node(&children[child_idx], child_rect, style, depth + 1, path, out);
+        path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn squarify_tree_assigns_depth_and_path_and_skips_zero_branches() {
+        let root = Node::branch(vec![
+            Node::leaf(50.0),
+            Node::branch(vec![Node::leaf(30.0), Node::leaf(20.0)]),
+            Node::branch(vec![]),
+            Node::leaf(0.0),
+        ]);
+
+        let placed = squarify_tree(&root, 0.0, 0.0, 100.0, 100.0, 0.0, 0.0);
+
+        // The root branch itself, its two positive-value children, and the
+        // two leaves nested under the second branch.
+        assert_eq!(placed.len(), 5);
+
+        let root_rect = placed.iter().find(|p| p.depth == 0).unwrap();
+        assert_eq!(root_rect.path, Vec::<usize>::new());
+
+        let nested_leaves: Vec<_> = placed.iter().filter(|p| p.depth == 2).collect();
+        assert_eq!(nested_leaves.len(), 2);
+        for leaf in &nested_leaves {
+            assert_eq!(leaf.path.len(), 2);
+            assert_eq!(leaf.path[0], 1);
+        }
+
+        // Neither the empty branch (index 2) nor the zero-value leaf
+        // (index 3) produced a rectangle.
+        let depth_one: Vec<_> = placed.iter().filter(|p| p.depth == 1).collect();
+        let depth_one_indices: Vec<usize> = depth_one.iter().map(|p| p.path[0]).collect();
+        assert!(!depth_one_indices.contains(&2));
+        assert!(!depth_one_indices.contains(&3));
+    }
+}